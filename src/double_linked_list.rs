@@ -1,39 +1,49 @@
-use std::cell::RefCell;
-use std::rc::Rc;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
 
-type LinkNode<T> = Option<Rc<RefCell<Node<T>>>>;
+type Link<T> = Option<NonNull<Node<T>>>;
 
-struct Node<T> {
-    value: T,
-    prev: LinkNode<T>,
-    next: LinkNode<T>,
+pub(crate) struct Node<T> {
+    pub(crate) value: T,
+    prev: Link<T>,
+    next: Link<T>,
 }
 
 impl<T> Node<T> {
-    pub fn new(value: T) -> Rc<RefCell<Self>> {
-        Rc::new(RefCell::new(Node {
+    fn new(value: T) -> Self {
+        Node {
             value,
             prev: None,
             next: None,
-        }))
+        }
+    }
+
+    /// Consume a boxed node and recover its owned value, used by
+    /// `pop_front`/`pop_back`/`remove` to hand back ownership once the node
+    /// has been unlinked.
+    // The `Box<Self>` receiver is intentional: callers reconstitute the node
+    // with `Box::from_raw(...)` and hand the box straight here to free it.
+    #[allow(clippy::boxed_local)]
+    pub(crate) fn into_val(self: Box<Self>) -> T {
+        self.value
     }
 }
 
-struct DoubleLinkedList<T> {
-    head: LinkNode<T>,
-    tail: LinkNode<T>,
+pub struct DoubleLinkedList<T> {
+    head: Link<T>,
+    tail: Link<T>,
     length: usize,
+    // We logically own the boxed nodes reached through `head`/`tail`.
+    marker: PhantomData<Box<Node<T>>>,
 }
 
-impl<T> DoubleLinkedList<T>
-where
-    T: PartialEq,
-{
+impl<T> DoubleLinkedList<T> {
     pub fn new() -> Self {
         DoubleLinkedList {
             head: None,
             tail: None,
             length: 0,
+            marker: PhantomData,
         }
     }
 
@@ -46,125 +56,554 @@ where
     }
 
     pub fn add(&mut self, value: T) {
-        let head = Node::new(value);
-        match self.head.take() {
-            Some(old_head) => {
-                old_head.borrow_mut().prev = Some(head.clone());
-                head.borrow_mut().next = Some(old_head.clone());
-            }
-            None => {
-                self.tail = Some(head.clone());
+        self.push_front(value)
+    }
+
+    pub fn append(&mut self, value: T) {
+        self.push_back(value)
+    }
+
+    /// Push a value onto the front of the list in O(1).
+    pub fn push_front(&mut self, value: T) {
+        self.push_front_ptr(value);
+    }
+
+    /// Push a value onto the back of the list in O(1).
+    pub fn push_back(&mut self, value: T) {
+        let node = NonNull::from(Box::leak(Box::new(Node::new(value))));
+        // SAFETY: `node` was just allocated and the `tail`/`head` links are
+        // maintained as valid for the lifetime of the list.
+        unsafe {
+            (*node.as_ptr()).prev = self.tail;
+            match self.tail {
+                Some(tail) => (*tail.as_ptr()).next = Some(node),
+                None => self.head = Some(node),
             }
         }
-        self.head = Some(head.clone());
+        self.tail = Some(node);
         self.length += 1;
     }
 
-    pub fn append(&mut self, value: T) {
-        let tail = Node::new(value);
-        match self.tail.take() {
-            Some(old_tail) => {
-                old_tail.borrow_mut().next = Some(tail.clone());
-                tail.borrow_mut().prev = Some(old_tail);
+    /// Unlink the head node in O(1) and return its owned value, collapsing the
+    /// list to empty when the last element is removed.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|node| {
+            // SAFETY: `node` is a live boxed node reachable from `head`.
+            let boxed = unsafe { Box::from_raw(node.as_ptr()) };
+            self.head = boxed.next;
+            match self.head {
+                Some(head) => unsafe { (*head.as_ptr()).prev = None },
+                None => self.tail = None,
             }
-            None => {
-                self.head = Some(tail.clone());
+            self.length -= 1;
+            boxed.into_val()
+        })
+    }
+
+    /// Unlink the tail node in O(1) and return its owned value, collapsing the
+    /// list to empty when the last element is removed.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.map(|node| {
+            // SAFETY: `node` is a live boxed node reachable from `tail`.
+            let boxed = unsafe { Box::from_raw(node.as_ptr()) };
+            self.tail = boxed.prev;
+            match self.tail {
+                Some(tail) => unsafe { (*tail.as_ptr()).next = None },
+                None => self.head = None,
             }
-        }
-        self.tail = Some(tail);
-        self.length += 1;
+            self.length -= 1;
+            boxed.into_val()
+        })
+    }
+
+    /// Borrow the value at the front of the list, if any.
+    pub fn front(&self) -> Option<&T> {
+        // SAFETY: `head` is either `None` or a live boxed node.
+        self.head.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    /// Borrow the value at the back of the list, if any.
+    pub fn back(&self) -> Option<&T> {
+        // SAFETY: `tail` is either `None` or a live boxed node.
+        self.tail.map(|node| unsafe { &(*node.as_ptr()).value })
     }
 
     pub fn insert(&mut self, value: T, index: usize) {
         if index == 0 {
-            self.add(value)
+            self.push_front(value)
         } else if index >= self.length {
-            self.append(value)
+            self.push_back(value)
         } else {
-            let node = Node::new(value);
-            let old_node = self
-                .traverse(|_| true)
-                .nth(index)
-                .expect("index out of range");
-
-            let old_node_prev = old_node.borrow_mut().prev.take();
-            node.borrow_mut().next = Some(old_node.clone());
-            node.borrow_mut().prev = old_node_prev.clone();
-            old_node.borrow_mut().prev = Some(node.clone());
-
-            if let Some(old_node_prev) = old_node_prev {
-                old_node_prev.borrow_mut().next = Some(node.clone());
+            let mut cur = self.head;
+            for _ in 0..index {
+                // SAFETY: `index < length`, so each `next` step lands on a node.
+                cur = unsafe { (*cur.unwrap().as_ptr()).next };
+            }
+            let cur = cur.unwrap();
+            let node = NonNull::from(Box::leak(Box::new(Node::new(value))));
+            // SAFETY: `node` is freshly allocated and `cur` is a live interior
+            // node with a predecessor (guaranteed by `index >= 1`).
+            unsafe {
+                let prev = (*cur.as_ptr()).prev;
+                (*node.as_ptr()).next = Some(cur);
+                (*node.as_ptr()).prev = prev;
+                (*cur.as_ptr()).prev = Some(node);
+                match prev {
+                    Some(prev) => (*prev.as_ptr()).next = Some(node),
+                    None => self.head = Some(node),
+                }
             }
-
             self.length += 1;
         }
     }
 
-    pub fn search(&self, value: T) -> bool {
-        self.traverse(|node| node.borrow().value == value)
-            .next()
-            .is_some()
+    pub fn traverse<F>(&self, f: F) -> impl DoubleEndedIterator<Item = &T>
+    where
+        F: Fn(&T) -> bool,
+    {
+        NodeIter {
+            front: self.head,
+            back: self.tail,
+            remaining: self.length,
+            f,
+            marker: PhantomData,
+        }
     }
 
-    pub fn remove(&mut self, value: T) -> Option<T> {
-        if let Some(node) = self.traverse(|node| node.borrow().value == value).next() {
-            let prev = node.borrow().prev.clone();
-            let next = node.borrow().next.clone();
+    /// Like [`traverse`](Self::traverse) but walks from the tail towards the
+    /// head, following the `prev` links a singly linked list could not offer.
+    pub fn traverse_rev<F>(&self, f: F) -> impl Iterator<Item = &T>
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.traverse(f).rev()
+    }
 
-            match prev.clone() {
-                Some(prev) => prev.borrow_mut().next = next.clone(),
-                None => self.head = next.clone(),
+    /// Push a value onto the front and return a pointer to the new node, so
+    /// callers such as the LRU cache can index it in a side table.
+    pub(crate) fn push_front_ptr(&mut self, value: T) -> NonNull<Node<T>> {
+        let node = NonNull::from(Box::leak(Box::new(Node::new(value))));
+        // SAFETY: `node` was just allocated and the link invariants hold.
+        unsafe {
+            (*node.as_ptr()).next = self.head;
+            match self.head {
+                Some(head) => (*head.as_ptr()).prev = Some(node),
+                None => self.tail = Some(node),
             }
+        }
+        self.head = Some(node);
+        self.length += 1;
+        node
+    }
 
-            match next {
-                Some(next) => next.borrow_mut().prev = prev.clone(),
-                None => self.tail = prev.clone(),
-            }
-            self.length -= 1;
+    /// The tail node pointer, used by callers (e.g. the LRU cache) that need to
+    /// reach an eviction candidate without walking the list.
+    pub(crate) fn back_node(&self) -> Link<T> {
+        self.tail
+    }
 
-            return Some(Rc::try_unwrap(node).ok().unwrap().into_inner().value);
+    /// Promote an existing node to the front of the list in O(1).
+    ///
+    /// # Safety
+    ///
+    /// `node` must be a live node currently owned by this list.
+    pub(crate) unsafe fn move_to_front(&mut self, node: NonNull<Node<T>>) {
+        if self.head == Some(node) {
+            return;
         }
-        None
+        // Detach from its current position (it has a predecessor because it is
+        // not the head).
+        let prev = (*node.as_ptr()).prev;
+        let next = (*node.as_ptr()).next;
+        if let Some(prev) = prev {
+            (*prev.as_ptr()).next = next;
+        }
+        match next {
+            Some(next) => (*next.as_ptr()).prev = prev,
+            None => self.tail = prev,
+        }
+        // Splice at the head.
+        (*node.as_ptr()).prev = None;
+        (*node.as_ptr()).next = self.head;
+        if let Some(head) = self.head {
+            (*head.as_ptr()).prev = Some(node);
+        }
+        self.head = Some(node);
     }
 
-    pub fn traverse<F>(&self, f: F) -> impl Iterator<Item = Rc<RefCell<Node<T>>>>
-    where
-        F: Fn(&Rc<RefCell<Node<T>>>) -> bool,
-    {
-        NodeIter {
-            next: self.head.clone(),
-            f,
+    /// Unlink an arbitrary live node in O(1), fixing up `head`/`tail` and the
+    /// neighbouring links and decrementing the length. The node itself is left
+    /// dangling for the caller to reclaim.
+    ///
+    /// # Safety
+    ///
+    /// `node` must be a live node currently owned by this list.
+    unsafe fn unlink_node(&mut self, node: NonNull<Node<T>>) {
+        let prev = (*node.as_ptr()).prev;
+        let next = (*node.as_ptr()).next;
+        match prev {
+            Some(prev) => (*prev.as_ptr()).next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => (*next.as_ptr()).prev = prev,
+            None => self.tail = prev,
+        }
+        self.length -= 1;
+    }
+
+    /// A mutable cursor positioned at the head of the list.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.head,
+            list: self,
+        }
+    }
+
+    /// A mutable cursor positioned at the tail of the list.
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.tail,
+            list: self,
+        }
+    }
+
+    /// A borrowing iterator yielding `&T` from the head to the tail.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            front: self.head,
+            back: self.tail,
+            remaining: self.length,
+            marker: PhantomData,
+        }
+    }
+
+    /// A borrowing iterator yielding `&mut T` from the head to the tail.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            front: self.head,
+            back: self.tail,
+            remaining: self.length,
+            marker: PhantomData,
         }
     }
 }
 
-struct NodeIter<T, F>
+impl<T> DoubleLinkedList<T>
 where
     T: PartialEq,
-    F: Fn(&Rc<RefCell<Node<T>>>) -> bool,
 {
-    next: Option<Rc<RefCell<Node<T>>>>,
+    pub fn search(&self, value: T) -> bool {
+        self.traverse(|node| *node == value).next().is_some()
+    }
+
+    pub fn remove(&mut self, value: T) -> Option<T> {
+        let mut cur = self.head;
+        while let Some(node) = cur {
+            // SAFETY: `node` is a live boxed node reachable from `head`.
+            if unsafe { (*node.as_ptr()).value == value } {
+                unsafe {
+                    self.unlink_node(node);
+                    return Some(Box::from_raw(node.as_ptr()).into_val());
+                }
+            }
+            cur = unsafe { (*node.as_ptr()).next };
+        }
+        None
+    }
+}
+
+impl<T> Default for DoubleLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for DoubleLinkedList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+struct NodeIter<'a, T, F>
+where
+    F: Fn(&T) -> bool,
+{
+    front: Link<T>,
+    back: Link<T>,
+    remaining: usize,
     f: F,
+    marker: PhantomData<&'a T>,
 }
 
-impl<T, F> Iterator for NodeIter<T, F>
+impl<'a, T, F> Iterator for NodeIter<'a, T, F>
 where
-    T: PartialEq,
-    F: Fn(&Rc<RefCell<Node<T>>>) -> bool,
+    F: Fn(&T) -> bool,
 {
-    type Item = Rc<RefCell<Node<T>>>;
+    type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some(node) = self.next.clone() {
-            self.next = node.borrow().next.clone();
-            if (self.f)(&node) {
-                return Some(node);
+        while self.remaining > 0 {
+            let node = self.front.unwrap();
+            // SAFETY: `remaining` guarantees `front` points at a live node.
+            self.front = unsafe { (*node.as_ptr()).next };
+            self.remaining -= 1;
+            let value = unsafe { &(*node.as_ptr()).value };
+            if (self.f)(value) {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+impl<'a, T, F> DoubleEndedIterator for NodeIter<'a, T, F>
+where
+    F: Fn(&T) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            let node = self.back.unwrap();
+            // SAFETY: `remaining` guarantees `back` points at a live node.
+            self.back = unsafe { (*node.as_ptr()).prev };
+            self.remaining -= 1;
+            let value = unsafe { &(*node.as_ptr()).value };
+            if (self.f)(value) {
+                return Some(value);
             }
         }
         None
     }
 }
 
+/// A cursor into a [`DoubleLinkedList`] that supports in-place navigation,
+/// insertion, and removal relative to the current node without re-scanning
+/// from the head for every mutation.
+pub struct CursorMut<'a, T> {
+    list: &'a mut DoubleLinkedList<T>,
+    current: Link<T>,
+}
+
+impl<T> CursorMut<'_, T> {
+    /// Advance the cursor towards the tail.
+    pub fn move_next(&mut self) {
+        // SAFETY: `current` is `None` or a live node owned by the list.
+        self.current = self
+            .current
+            .and_then(|node| unsafe { (*node.as_ptr()).next });
+    }
+
+    /// Advance the cursor towards the head.
+    pub fn move_prev(&mut self) {
+        // SAFETY: `current` is `None` or a live node owned by the list.
+        self.current = self
+            .current
+            .and_then(|node| unsafe { (*node.as_ptr()).prev });
+    }
+
+    /// Borrow the value under the cursor for mutation.
+    pub fn current(&mut self) -> Option<&mut T> {
+        // SAFETY: `current` is `None` or a live node owned by the list.
+        self.current
+            .map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    /// Splice a new node immediately before the current one in O(1). With no
+    /// current node (empty list or past the end) the value is appended.
+    pub fn insert_before(&mut self, value: T) {
+        match self.current {
+            Some(cur) => {
+                let node = NonNull::from(Box::leak(Box::new(Node::new(value))));
+                // SAFETY: `node` is fresh and `cur` is a live node of the list.
+                unsafe {
+                    let prev = (*cur.as_ptr()).prev;
+                    (*node.as_ptr()).next = Some(cur);
+                    (*node.as_ptr()).prev = prev;
+                    (*cur.as_ptr()).prev = Some(node);
+                    match prev {
+                        Some(prev) => (*prev.as_ptr()).next = Some(node),
+                        None => self.list.head = Some(node),
+                    }
+                }
+                self.list.length += 1;
+            }
+            None => self.list.push_back(value),
+        }
+    }
+
+    /// Splice a new node immediately after the current one in O(1). With no
+    /// current node (empty list or past the end) the value is prepended.
+    pub fn insert_after(&mut self, value: T) {
+        match self.current {
+            Some(cur) => {
+                let node = NonNull::from(Box::leak(Box::new(Node::new(value))));
+                // SAFETY: `node` is fresh and `cur` is a live node of the list.
+                unsafe {
+                    let next = (*cur.as_ptr()).next;
+                    (*node.as_ptr()).prev = Some(cur);
+                    (*node.as_ptr()).next = next;
+                    (*cur.as_ptr()).next = Some(node);
+                    match next {
+                        Some(next) => (*next.as_ptr()).prev = Some(node),
+                        None => self.list.tail = Some(node),
+                    }
+                }
+                self.list.length += 1;
+            }
+            None => self.list.push_front(value),
+        }
+    }
+
+    /// Unlink the node under the cursor, advance to its successor, and return
+    /// the owned value.
+    pub fn remove_current(&mut self) -> Option<T> {
+        self.current.map(|node| {
+            // SAFETY: `current` is a live node owned by the list.
+            unsafe {
+                let next = (*node.as_ptr()).next;
+                self.list.unlink_node(node);
+                self.current = next;
+                Box::from_raw(node.as_ptr()).into_val()
+            }
+        })
+    }
+}
+
+/// Owning iterator that pops values off the front of the list.
+pub struct IntoIter<T> {
+    list: DoubleLinkedList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+/// Borrowing iterator yielding `&T`.
+pub struct Iter<'a, T> {
+    front: Link<T>,
+    back: Link<T>,
+    remaining: usize,
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.front.unwrap();
+        // SAFETY: `remaining` guarantees `front` points at a live node.
+        self.front = unsafe { (*node.as_ptr()).next };
+        self.remaining -= 1;
+        Some(unsafe { &(*node.as_ptr()).value })
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.back.unwrap();
+        // SAFETY: `remaining` guarantees `back` points at a live node.
+        self.back = unsafe { (*node.as_ptr()).prev };
+        self.remaining -= 1;
+        Some(unsafe { &(*node.as_ptr()).value })
+    }
+}
+
+/// Borrowing iterator yielding `&mut T`.
+pub struct IterMut<'a, T> {
+    front: Link<T>,
+    back: Link<T>,
+    remaining: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.front.unwrap();
+        // SAFETY: `remaining` guarantees `front` points at a live node and the
+        // `&mut T` borrows are disjoint because each node is visited once.
+        self.front = unsafe { (*node.as_ptr()).next };
+        self.remaining -= 1;
+        Some(unsafe { &mut (*node.as_ptr()).value })
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.back.unwrap();
+        // SAFETY: see `next`; front and back never meet on the same node.
+        self.back = unsafe { (*node.as_ptr()).prev };
+        self.remaining -= 1;
+        Some(unsafe { &mut (*node.as_ptr()).value })
+    }
+}
+
+impl<T> IntoIterator for DoubleLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DoubleLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut DoubleLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> Extend<T> for DoubleLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for DoubleLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = DoubleLinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::double_linked_list::DoubleLinkedList;
@@ -179,7 +618,7 @@ mod tests {
         double_linked_list.append(4);
         double_linked_list
             .traverse(|_| true)
-            .for_each(|node| print!("{:?}-", node.borrow().value));
+            .for_each(|value| print!("{:?}-", value));
         println!("\n");
 
         println!("Insert 3 in index 2, and insert 5 in index 4:");
@@ -187,21 +626,21 @@ mod tests {
         double_linked_list.insert(5, 4);
         double_linked_list
             .traverse(|_| true)
-            .for_each(|node| print!("{:?}-", node.borrow().value));
+            .for_each(|value| print!("{:?}-", value));
         println!("\n");
 
         println!("Add 0 as head:");
         double_linked_list.add(0);
         double_linked_list
             .traverse(|_| true)
-            .for_each(|node| print!("{:?}-", node.borrow().value));
+            .for_each(|value| print!("{:?}-", value));
         println!("\n");
 
         println!("Remove node 4:");
         double_linked_list.remove(4);
         double_linked_list
             .traverse(|_| true)
-            .for_each(|node| print!("{:?}-", node.borrow().value));
+            .for_each(|value| print!("{:?}-", value));
         println!("\n");
 
         println!("Check that node 3 exists:");
@@ -212,4 +651,53 @@ mod tests {
         println!("The double-linked list length:");
         println!("{:?}", double_linked_list.length);
     }
+
+    #[test]
+    fn test_iterator_traits() {
+        println!("Collect 1,2,3 into a list via FromIterator:");
+        let mut double_linked_list: DoubleLinkedList<i32> = (1..=3).collect();
+
+        println!("Extend with 4,5:");
+        double_linked_list.extend([4, 5]);
+
+        println!("Double every element through iter_mut:");
+        for value in &mut double_linked_list {
+            *value *= 2;
+        }
+
+        println!("Borrow each element through &list:");
+        for value in &double_linked_list {
+            print!("{:?}-", value);
+        }
+        println!("\n");
+
+        println!("Consume the list through IntoIterator:");
+        double_linked_list
+            .into_iter()
+            .for_each(|value| print!("{:?}-", value));
+        println!();
+    }
+
+    #[test]
+    fn test_cursor_mut() {
+        let mut double_linked_list = DoubleLinkedList::new();
+        double_linked_list.append(1);
+        double_linked_list.append(2);
+        double_linked_list.append(4);
+
+        println!("Insert 3 before node 4 and remove node 2 via a cursor:");
+        let mut cursor = double_linked_list.cursor_front_mut();
+        cursor.move_next();
+        let removed = cursor.remove_current();
+        println!("Removed->{:?}", removed);
+        cursor.insert_before(3);
+
+        double_linked_list
+            .traverse(|_| true)
+            .for_each(|value| print!("{:?}-", value));
+        println!("\n");
+
+        println!("The double-linked list length:");
+        println!("{:?}", double_linked_list.length);
+    }
 }
@@ -0,0 +1,5 @@
+pub mod double_linked_list;
+pub mod lru_cache;
+
+pub use double_linked_list::{CursorMut, DoubleLinkedList, IntoIter, Iter, IterMut};
+pub use lru_cache::LruCache;
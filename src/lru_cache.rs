@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ptr::NonNull;
+
+use crate::double_linked_list::{DoubleLinkedList, Node};
+
+/// A least-recently-used cache built on top of [`DoubleLinkedList`].
+///
+/// Recency order lives in the list — the most recently touched entry sits at
+/// the head, the eviction candidate at the tail — while a side `HashMap` maps
+/// each key to its node so lookups and promotions are O(1) rather than the
+/// O(n) scan `traverse().nth()` would cost. The list owns the nodes; the map
+/// only holds non-owning pointers into them.
+pub struct LruCache<K, V> {
+    list: DoubleLinkedList<(K, V)>,
+    map: HashMap<K, NonNull<Node<(K, V)>>>,
+    capacity: usize,
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    pub fn new(capacity: usize) -> Self {
+        LruCache {
+            list: DoubleLinkedList::new(),
+            map: HashMap::new(),
+            capacity,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.length()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Look up a key, promoting its node to the head so it becomes the most
+    /// recently used entry. Returns a reference to the stored value.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let node = *self.map.get(key)?;
+        // SAFETY: `node` is owned by `self.list` and stays live until evicted.
+        unsafe {
+            self.list.move_to_front(node);
+            Some(&(*node.as_ptr()).value.1)
+        }
+    }
+
+    /// Insert or update a key. An existing key has its value replaced and its
+    /// node promoted to the head; a new key is inserted at the head and the
+    /// tail evicted once the configured capacity is exceeded.
+    pub fn put(&mut self, key: K, value: V) {
+        if let Some(&node) = self.map.get(&key) {
+            // SAFETY: `node` is owned by `self.list` and still live.
+            unsafe {
+                (*node.as_ptr()).value.1 = value;
+                self.list.move_to_front(node);
+            }
+            return;
+        }
+
+        let node = self.list.push_front_ptr((key.clone(), value));
+        self.map.insert(key, node);
+
+        if self.list.length() > self.capacity {
+            if let Some(tail) = self.list.back_node() {
+                // SAFETY: `tail` is live until the `pop_back` below frees it.
+                let evicted = unsafe { (*tail.as_ptr()).value.0.clone() };
+                self.list.pop_back();
+                self.map.remove(&evicted);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lru_cache::LruCache;
+
+    #[test]
+    fn test_lru_cache() {
+        let mut cache = LruCache::new(2);
+
+        println!("Put (1,1) and (2,2):");
+        cache.put(1, 1);
+        cache.put(2, 2);
+        println!("len={:?} capacity={:?}", cache.len(), cache.capacity());
+
+        println!("Get 1 (promotes it to the head):");
+        println!("{:?}", cache.get(&1).copied());
+
+        println!("Put (3,3), which should evict the least recently used key 2:");
+        cache.put(3, 3);
+        println!("Has 2->{:?}", cache.get(&2).copied());
+        println!("Has 1->{:?}", cache.get(&1).copied());
+        println!("Has 3->{:?}", cache.get(&3).copied());
+
+        println!("The cache length:");
+        println!("{:?}", cache.len());
+    }
+}